@@ -1,11 +1,11 @@
 #![allow(dead_code, unused)]
 #[macro_use]
-use std::{collections::HashMap, fs};
+use std::{collections::HashMap, fs, ops::Index};
 
 use thiserror::Error;
 
 #[derive(Debug, PartialEq)]
-enum JsonValue {
+pub enum JsonValue {
     Null,
     Bool(bool),
     Number(f64),
@@ -14,12 +14,320 @@ enum JsonValue {
     Object(HashMap<String, JsonValue>),
 }
 
+impl JsonValue {
+    pub fn to_string_pretty(&self, indent: usize) -> String {
+        let mut out = String::new();
+        self.write_pretty(&mut out, indent, 0);
+        out
+    }
+
+    pub fn as_object(&self) -> Option<&HashMap<String, JsonValue>> {
+        match self {
+            JsonValue::Object(entries) => Some(entries),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<JsonValue>> {
+        match self {
+            JsonValue::Array(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            JsonValue::String(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    pub fn as_f64(&self) -> Option<f64> {
+        match self {
+            JsonValue::Number(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            JsonValue::Bool(value) => Some(*value),
+            _ => None,
+        }
+    }
+
+    pub fn get(&self, key: &str) -> Option<&JsonValue> {
+        self.as_object()?.get(key)
+    }
+
+    fn write_compact(&self, out: &mut String) {
+        match self {
+            JsonValue::Null => out.push_str("null"),
+            JsonValue::Bool(value) => out.push_str(if *value { "true" } else { "false" }),
+            // JSON has no token for NaN/Infinity; emit `null`, matching JSON.stringify.
+            JsonValue::Number(value) if value.is_finite() => out.push_str(&value.to_string()),
+            JsonValue::Number(_) => out.push_str("null"),
+            JsonValue::String(value) => write_escaped_string(value, out),
+            JsonValue::Array(items) => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    item.write_compact(out);
+                }
+                out.push(']');
+            }
+            JsonValue::Object(entries) => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    write_escaped_string(key, out);
+                    out.push(':');
+                    value.write_compact(out);
+                }
+                out.push('}');
+            }
+        }
+    }
+
+    fn write_pretty(&self, out: &mut String, indent: usize, depth: usize) {
+        match self {
+            JsonValue::Array(items) if !items.is_empty() => {
+                out.push('[');
+                for (i, item) in items.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                    out.push_str(&" ".repeat(indent * (depth + 1)));
+                    item.write_pretty(out, indent, depth + 1);
+                }
+                out.push('\n');
+                out.push_str(&" ".repeat(indent * depth));
+                out.push(']');
+            }
+            JsonValue::Object(entries) if !entries.is_empty() => {
+                out.push('{');
+                for (i, (key, value)) in entries.iter().enumerate() {
+                    if i > 0 {
+                        out.push(',');
+                    }
+                    out.push('\n');
+                    out.push_str(&" ".repeat(indent * (depth + 1)));
+                    write_escaped_string(key, out);
+                    out.push_str(": ");
+                    value.write_pretty(out, indent, depth + 1);
+                }
+                out.push('\n');
+                out.push_str(&" ".repeat(indent * depth));
+                out.push('}');
+            }
+            _ => self.write_compact(out),
+        }
+    }
+}
+
+impl std::fmt::Display for JsonValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut out = String::new();
+        self.write_compact(&mut out);
+        f.write_str(&out)
+    }
+}
+
+fn write_escaped_string(value: &str, out: &mut String) {
+    out.push('"');
+    for char in value.chars() {
+        match char {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            '\u{0008}' => out.push_str("\\b"),
+            '\u{000C}' => out.push_str("\\f"),
+            char if (char as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", char as u32)),
+            char => out.push(char),
+        }
+    }
+    out.push('"');
+}
+
+impl Index<&str> for JsonValue {
+    type Output = JsonValue;
+
+    fn index(&self, key: &str) -> &JsonValue {
+        static NULL: JsonValue = JsonValue::Null;
+        self.get(key).unwrap_or(&NULL)
+    }
+}
+
+impl Index<usize> for JsonValue {
+    type Output = JsonValue;
+
+    fn index(&self, index: usize) -> &JsonValue {
+        static NULL: JsonValue = JsonValue::Null;
+        self.as_array().and_then(|items| items.get(index)).unwrap_or(&NULL)
+    }
+}
+
+#[derive(Error, Debug, PartialEq)]
+#[error("expected {expected} at `{path}`")]
+pub struct JsonDecodeError {
+    expected: &'static str,
+    path: String,
+}
+
+impl JsonDecodeError {
+    fn type_mismatch(expected: &'static str) -> Self {
+        JsonDecodeError {
+            expected,
+            path: String::new(),
+        }
+    }
+
+    fn prefixed_with_field(mut self, field: &str) -> Self {
+        self.path = if self.path.is_empty() {
+            field.to_string()
+        } else if self.path.starts_with('[') {
+            format!("{}{}", field, self.path)
+        } else {
+            format!("{}.{}", field, self.path)
+        };
+        self
+    }
+
+    fn prefixed_with_index(mut self, index: usize) -> Self {
+        self.path = if self.path.is_empty() {
+            format!("[{}]", index)
+        } else if self.path.starts_with('[') {
+            format!("[{}]{}", index, self.path)
+        } else {
+            format!("[{}].{}", index, self.path)
+        };
+        self
+    }
+}
+
+pub trait FromJson: Sized {
+    fn from_json(value: &JsonValue) -> Result<Self, JsonDecodeError>;
+}
+
+impl FromJson for bool {
+    fn from_json(value: &JsonValue) -> Result<Self, JsonDecodeError> {
+        value
+            .as_bool()
+            .ok_or_else(|| JsonDecodeError::type_mismatch("bool"))
+    }
+}
+
+impl FromJson for f64 {
+    fn from_json(value: &JsonValue) -> Result<Self, JsonDecodeError> {
+        value
+            .as_f64()
+            .ok_or_else(|| JsonDecodeError::type_mismatch("number"))
+    }
+}
+
+impl FromJson for i64 {
+    fn from_json(value: &JsonValue) -> Result<Self, JsonDecodeError> {
+        let number = value
+            .as_f64()
+            .ok_or_else(|| JsonDecodeError::type_mismatch("number"))?;
+        if number.fract() != 0.0 || number < i64::MIN as f64 || number > i64::MAX as f64 {
+            return Err(JsonDecodeError::type_mismatch("integer"));
+        }
+        Ok(number as i64)
+    }
+}
+
+impl FromJson for String {
+    fn from_json(value: &JsonValue) -> Result<Self, JsonDecodeError> {
+        value
+            .as_str()
+            .map(str::to_string)
+            .ok_or_else(|| JsonDecodeError::type_mismatch("string"))
+    }
+}
+
+impl<T: FromJson> FromJson for Vec<T> {
+    fn from_json(value: &JsonValue) -> Result<Self, JsonDecodeError> {
+        let items = value
+            .as_array()
+            .ok_or_else(|| JsonDecodeError::type_mismatch("array"))?;
+        items
+            .iter()
+            .enumerate()
+            .map(|(index, item)| T::from_json(item).map_err(|e| e.prefixed_with_index(index)))
+            .collect()
+    }
+}
+
+impl<T: FromJson> FromJson for HashMap<String, T> {
+    fn from_json(value: &JsonValue) -> Result<Self, JsonDecodeError> {
+        let entries = value
+            .as_object()
+            .ok_or_else(|| JsonDecodeError::type_mismatch("object"))?;
+        entries
+            .iter()
+            .map(|(key, item)| {
+                T::from_json(item)
+                    .map(|parsed| (key.clone(), parsed))
+                    .map_err(|e| e.prefixed_with_field(key))
+            })
+            .collect()
+    }
+}
+
+impl<T: FromJson> FromJson for Option<T> {
+    fn from_json(value: &JsonValue) -> Result<Self, JsonDecodeError> {
+        match value {
+            JsonValue::Null => Ok(None),
+            _ => T::from_json(value).map(Some),
+        }
+    }
+}
+
 struct JsonParser {
     chars: Vec<char>,
     cursor: usize,
+    position: Position,
 }
 
-#[derive(Error, Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    pub line: usize,
+    pub column: usize,
+    pub offset: usize,
+}
+
+impl Position {
+    fn start() -> Self {
+        Position {
+            line: 1,
+            column: 1,
+            offset: 0,
+        }
+    }
+}
+
+impl std::fmt::Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(f, "{}:{}", self.line, self.column)
+    }
+}
+
+#[derive(Error, Debug)]
+#[error("{position}: {error}")]
+pub struct LocatedError {
+    pub error: JsonParserError,
+    pub position: Position,
+}
+
+#[derive(Error, Debug)]
 pub enum JsonParserError {
     #[error("expected end of a value")]
     NoEnd,
@@ -27,8 +335,22 @@ pub enum JsonParserError {
     InvalidChar(char, char),
     #[error("invalid number `{0}`")]
     InvalidNumber(String),
+    #[error("expected an escape character after `\\`")]
+    ExpectedEscapeChar,
+    #[error("invalid unicode escape")]
+    InvalidUnicodeEscape,
+    #[error("invalid surrogate pair")]
+    InvalidSurrogatePair,
+    #[error("unescaped control character `{0:?}` in string")]
+    UnescapedControlChar(char),
+    #[error("expected end of input, got `{0}`")]
+    ExpectedEndOfInput(char),
     #[error("end of file")]
     Eof,
+    #[error("invalid utf-8 input")]
+    InvalidUtf8,
+    #[error("io error: {0}")]
+    Io(#[from] std::io::Error),
     #[error("unknown json parser error")]
     Unknown,
 }
@@ -37,12 +359,27 @@ type JsonResult = Result<JsonValue, JsonParserError>;
 
 impl JsonParser {
     fn new(chars: Vec<char>) -> Self {
-        JsonParser { chars, cursor: 0 }
+        JsonParser {
+            chars,
+            cursor: 0,
+            position: Position::start(),
+        }
+    }
+
+    fn advance_position(&mut self, char: char) {
+        if char == '\n' {
+            self.position.line += 1;
+            self.position.column = 1;
+        } else {
+            self.position.column += 1;
+        }
+        self.position.offset += 1;
     }
 
     fn chop(&mut self) {
-        while let Some(char) = self.chars.get(self.cursor) {
+        while let Some(char) = self.chars.get(self.cursor).copied() {
             if char.is_whitespace() {
+                self.advance_position(char);
                 self.cursor += 1
             } else {
                 break;
@@ -60,6 +397,7 @@ impl JsonParser {
     fn consume(&mut self) -> Result<char, JsonParserError> {
         let res = self.read()?;
         self.cursor += 1;
+        self.advance_position(res);
         Ok(res)
     }
 
@@ -81,6 +419,13 @@ impl JsonParser {
                 end = true;
                 break;
             }
+            if char == '\\' {
+                text.push(self.parse_escape()?);
+                continue;
+            }
+            if (char as u32) < 0x20 {
+                return Err(JsonParserError::UnescapedControlChar(char));
+            }
             text.push(char);
         }
         if end {
@@ -90,6 +435,100 @@ impl JsonParser {
         }
     }
 
+    fn parse_escape(&mut self) -> Result<char, JsonParserError> {
+        let escape = self.consume()?;
+        match escape {
+            '"' => Ok('"'),
+            '\\' => Ok('\\'),
+            '/' => Ok('/'),
+            'b' => Ok('\u{0008}'),
+            'f' => Ok('\u{000C}'),
+            'n' => Ok('\n'),
+            'r' => Ok('\r'),
+            't' => Ok('\t'),
+            'u' => {
+                let high = self.read_hex4()?;
+                if (0xD800..=0xDBFF).contains(&high) {
+                    self.consume_check('\\')?;
+                    self.consume_check('u')?;
+                    let low = self.read_hex4()?;
+                    if !(0xDC00..=0xDFFF).contains(&low) {
+                        return Err(JsonParserError::InvalidSurrogatePair);
+                    }
+                    let code = 0x10000 + ((high - 0xD800) << 10) + (low - 0xDC00);
+                    char::from_u32(code).ok_or(JsonParserError::InvalidSurrogatePair)
+                } else if (0xDC00..=0xDFFF).contains(&high) {
+                    Err(JsonParserError::InvalidSurrogatePair)
+                } else {
+                    char::from_u32(high).ok_or(JsonParserError::InvalidUnicodeEscape)
+                }
+            }
+            _ => Err(JsonParserError::ExpectedEscapeChar),
+        }
+    }
+
+    fn read_hex4(&mut self) -> Result<u32, JsonParserError> {
+        let mut value = 0u32;
+        for _ in 0..4 {
+            let digit = self
+                .consume()?
+                .to_digit(16)
+                .ok_or(JsonParserError::InvalidUnicodeEscape)?;
+            value = value * 16 + digit;
+        }
+        Ok(value)
+    }
+
+    fn parse_number(&mut self) -> JsonResult {
+        let mut text = String::new();
+
+        if self.read()? == '-' {
+            text.push(self.consume()?);
+        }
+
+        let first = self.expect_digit(&text)?;
+        text.push(first);
+        if first != '0' {
+            self.consume_digits(&mut text);
+        }
+
+        if matches!(self.read(), Ok('.')) {
+            text.push(self.consume()?);
+            text.push(self.expect_digit(&text)?);
+            self.consume_digits(&mut text);
+        }
+
+        if matches!(self.read(), Ok('e') | Ok('E')) {
+            text.push(self.consume()?);
+            if matches!(self.read(), Ok('+') | Ok('-')) {
+                text.push(self.consume()?);
+            }
+            text.push(self.expect_digit(&text)?);
+            self.consume_digits(&mut text);
+        }
+
+        text.parse::<f64>()
+            .map(JsonValue::Number)
+            .map_err(|_| JsonParserError::InvalidNumber(text))
+    }
+
+    fn expect_digit(&mut self, text_so_far: &str) -> Result<char, JsonParserError> {
+        match self.read() {
+            Ok(char) if char.is_ascii_digit() => self.consume(),
+            _ => Err(JsonParserError::InvalidNumber(text_so_far.to_string())),
+        }
+    }
+
+    fn consume_digits(&mut self, text: &mut String) {
+        while let Ok(char) = self.read() {
+            if !char.is_ascii_digit() {
+                break;
+            }
+            text.push(char);
+            let _ = self.consume();
+        }
+    }
+
     fn parse_next(&mut self) -> JsonResult {
         self.chop();
         let char = self.read()?;
@@ -97,30 +536,11 @@ impl JsonParser {
             '{' => self.parse_object(),
             '"' => Ok(JsonValue::String(self.parse_string()?)),
             '[' => self.parse_array(),
+            '-' => self.parse_number(),
             _ => {
                 let mut text = String::new();
-                if char.is_numeric() {
-                    let mut found_point = false;
-                    while self.cursor < self.chars.len() {
-                        let char = self.read()?;
-                        if char == '.' {
-                            if !found_point {
-                                found_point = true
-                            } else {
-                                text.push(char);
-                                return Err(JsonParserError::InvalidNumber(text));
-                            }
-                        } else if !char.is_numeric() {
-                            break;
-                        }
-                        self.cursor += 1;
-                        text.push(char);
-                    }
-                    let number = match text.parse::<f64>() {
-                        Ok(n) => n,
-                        Err(_) => return Err(JsonParserError::InvalidNumber(text)),
-                    };
-                    return Ok(JsonValue::Number(number));
+                if char.is_ascii_digit() {
+                    return self.parse_number();
                 }
 
                 if char.is_alphabetic() {
@@ -129,8 +549,7 @@ impl JsonParser {
                         if !char.is_alphabetic() {
                             break;
                         }
-                        text.push(char);
-                        self.cursor += 1
+                        text.push(self.consume()?);
                     }
                     return match text.as_str() {
                         "null" => Ok(JsonValue::Null),
@@ -139,6 +558,7 @@ impl JsonParser {
                         _ => Err(JsonParserError::Unknown),
                     };
                 }
+                self.consume()?;
                 Err(JsonParserError::Unknown)
             }
         }
@@ -158,7 +578,7 @@ impl JsonParser {
                     return Err(JsonParserError::Unknown);
                 }
                 end = true;
-                self.cursor += 1;
+                self.consume()?;
                 break;
             }
 
@@ -197,7 +617,7 @@ impl JsonParser {
                     return Err(JsonParserError::Unknown);
                 }
                 end = true;
-                self.cursor += 1;
+                self.consume()?;
                 break;
             }
 
@@ -227,51 +647,234 @@ impl JsonParser {
         }
     }
 
-    fn parse(&mut self) -> JsonResult {
+    fn parse(&mut self) -> Result<JsonValue, LocatedError> {
+        self.chop();
+        let value = self
+            .parse_next()
+            .map_err(|error| LocatedError {
+                error,
+                position: self.position,
+            })?;
+
         self.chop();
-        self.parse_object()
+        if let Some(trailing) = self.chars.get(self.cursor).copied() {
+            return Err(LocatedError {
+                error: JsonParserError::ExpectedEndOfInput(trailing),
+                position: self.position,
+            });
+        }
+
+        Ok(value)
     }
 }
 
-fn parse_file(file_path: &str) -> JsonResult {
-    let content: Vec<char> = match fs::read_to_string(file_path) {
-        Ok(v) => v.chars().collect(),
-        Err(_) => return Err(JsonParserError::Unknown),
-    };
-    let mut parser = JsonParser::new(content);
+pub fn parse_str(input: &str) -> Result<JsonValue, LocatedError> {
+    let mut parser = JsonParser::new(input.chars().collect());
     parser.parse()
 }
 
+pub fn parse_bytes(input: &[u8]) -> Result<JsonValue, LocatedError> {
+    let input = std::str::from_utf8(input).map_err(|_| LocatedError {
+        error: JsonParserError::InvalidUtf8,
+        position: Position::start(),
+    })?;
+    parse_str(input)
+}
+
+pub fn parse_file(file_path: &str) -> Result<JsonValue, LocatedError> {
+    let content = fs::read_to_string(file_path).map_err(|err| LocatedError {
+        error: JsonParserError::Io(err),
+        position: Position::start(),
+    })?;
+    parse_str(&content)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
     #[test]
     fn all() {
-        let result = parse_file("./test.json");
-        let mut hash = HashMap::new();
+        let value = parse_str(
+            r#"{"hello":"world","number":100,"null":null,"true":true,"false":false,"array":[null]}"#,
+        )
+        .unwrap();
 
+        let mut hash = HashMap::new();
         hash.insert("hello".to_string(), JsonValue::String("world".to_string()));
-
         hash.insert("number".to_string(), JsonValue::Number(100.0));
-
         hash.insert("null".to_string(), JsonValue::Null);
-
         hash.insert("true".to_string(), JsonValue::Bool(true));
-
         hash.insert("false".to_string(), JsonValue::Bool(false));
+        hash.insert("array".to_string(), JsonValue::Array(vec![JsonValue::Null]));
 
-        let vec = vec![JsonValue::Null];
-        hash.insert("array".to_string(), JsonValue::Array(vec));
+        assert_eq!(value, JsonValue::Object(hash))
+    }
 
-        if let Err(e) = &result {
-            println!("{}", e);
-        }
+    #[test]
+    fn parse_bytes_accepts_valid_utf8() {
+        assert_eq!(parse_bytes(b"42").unwrap(), JsonValue::Number(42.0));
+    }
+
+    #[test]
+    fn parse_bytes_rejects_invalid_utf8() {
+        let err = parse_bytes(&[0xff, 0xfe]).unwrap_err();
+        assert!(matches!(err.error, JsonParserError::InvalidUtf8));
+    }
 
-        println!("{:?}", result);
+    #[test]
+    fn parse_file_reads_from_disk() {
+        let path = std::env::temp_dir().join("sjp_rs_parse_file_test.json");
+        fs::write(&path, r#"{"ok":true}"#).unwrap();
+        let value = parse_file(path.to_str().unwrap()).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(value.get("ok"), Some(&JsonValue::Bool(true)));
+    }
 
-        assert!(result == Ok(JsonValue::Object(hash)))
+    #[test]
+    fn parse_file_missing_returns_io_error() {
+        let err = parse_file("./definitely-does-not-exist.json").unwrap_err();
+        assert!(matches!(err.error, JsonParserError::Io(_)));
     }
 
-    // TODO: more tests
+    #[test]
+    fn string_escapes_are_decoded() {
+        let value = parse_str(r#""line\nbreak\t\"quoted\"""#).unwrap();
+        assert_eq!(value, JsonValue::String("line\nbreak\t\"quoted\"".to_string()));
+    }
+
+    #[test]
+    fn unicode_escape_is_decoded() {
+        let value = parse_str(concat!(r#""caf"#, r"\", r#"u00e9""#)).unwrap();
+        assert_eq!(value, JsonValue::String("café".to_string()));
+    }
+
+    #[test]
+    fn surrogate_pair_is_decoded() {
+        let value = parse_str(concat!(r#"""#, r"\", "ud83d", r"\", r#"ude00""#)).unwrap();
+        assert_eq!(value, JsonValue::String("😀".to_string()));
+    }
+
+    #[test]
+    fn lone_low_surrogate_is_rejected() {
+        let err = parse_str(r#""\udc00""#).unwrap_err();
+        assert!(matches!(err.error, JsonParserError::InvalidSurrogatePair));
+    }
+
+    #[test]
+    fn raw_control_character_is_rejected() {
+        let err = parse_str("\"line\nbreak\"").unwrap_err();
+        assert!(matches!(err.error, JsonParserError::UnescapedControlChar('\n')));
+    }
+
+    #[test]
+    fn negative_number_is_accepted() {
+        assert_eq!(parse_str("-5").unwrap(), JsonValue::Number(-5.0));
+    }
+
+    #[test]
+    fn exponent_with_sign_is_accepted() {
+        assert_eq!(parse_str("2.5E-3").unwrap(), JsonValue::Number(2.5e-3));
+    }
+
+    #[test]
+    fn leading_zero_is_rejected() {
+        assert!(parse_str("007").is_err());
+    }
+
+    #[test]
+    fn missing_fraction_digit_is_rejected() {
+        assert!(parse_str("1.").is_err());
+    }
+
+    #[test]
+    fn missing_leading_digit_is_rejected() {
+        assert!(parse_str(".5").is_err());
+    }
+
+    #[test]
+    fn compact_round_trip() {
+        let value = parse_str(r#"{"a":1,"b":[true,null,"hi\nthere"]}"#).unwrap();
+        assert_eq!(parse_str(&value.to_string()).unwrap(), value);
+    }
+
+    #[test]
+    fn pretty_round_trip() {
+        let value = parse_str(r#"{"a":1,"b":[true,null,"hi\nthere"]}"#).unwrap();
+        assert_eq!(parse_str(&value.to_string_pretty(2)).unwrap(), value);
+    }
+
+    #[test]
+    fn pretty_indents_nested_structures() {
+        let value = JsonValue::Array(vec![JsonValue::Number(1.0)]);
+        assert_eq!(value.to_string_pretty(2), "[\n  1\n]");
+    }
+
+    #[test]
+    fn vec_and_option_decode() {
+        let value = parse_str("[1,2,null]").unwrap();
+        let decoded = Vec::<Option<i64>>::from_json(&value).unwrap();
+        assert_eq!(decoded, vec![Some(1), Some(2), None]);
+    }
+
+    #[test]
+    fn hash_map_decode() {
+        let value = parse_str(r#"{"a":1,"b":2}"#).unwrap();
+        let decoded = HashMap::<String, i64>::from_json(&value).unwrap();
+        assert_eq!(decoded.get("a"), Some(&1));
+        assert_eq!(decoded.get("b"), Some(&2));
+    }
+
+    #[test]
+    fn decode_error_reports_path() {
+        let value = parse_str(r#"{"users":[{"age":"old"}]}"#).unwrap();
+        let users = value.get("users").unwrap();
+        let err = Vec::<HashMap<String, i64>>::from_json(users).unwrap_err();
+        assert_eq!(err.path, "[0].age");
+    }
+
+    #[test]
+    fn error_position_is_just_past_offending_character() {
+        let err = parse_str("[12345,@]").unwrap_err();
+        assert_eq!((err.position.line, err.position.column), (1, 9));
+    }
+
+    #[test]
+    fn error_position_tracks_newlines() {
+        let err = parse_str("{\n  \"a\": 1,\n  \"b\": }\n}").unwrap_err();
+        assert_eq!(err.position.line, 3);
+    }
+
+    #[test]
+    fn error_position_after_empty_array_and_object() {
+        let err = parse_str("[]@").unwrap_err();
+        assert_eq!((err.position.line, err.position.column), (1, 3));
+
+        let err = parse_str("{}@").unwrap_err();
+        assert_eq!((err.position.line, err.position.column), (1, 3));
+    }
+
+    #[test]
+    fn top_level_array_is_accepted() {
+        assert_eq!(
+            parse_str("[1,2,3]").unwrap(),
+            JsonValue::Array(vec![
+                JsonValue::Number(1.0),
+                JsonValue::Number(2.0),
+                JsonValue::Number(3.0),
+            ])
+        );
+    }
+
+    #[test]
+    fn top_level_scalar_is_accepted() {
+        assert_eq!(parse_str("\"hello\"").unwrap(), JsonValue::String("hello".to_string()));
+        assert_eq!(parse_str("true").unwrap(), JsonValue::Bool(true));
+        assert_eq!(parse_str("42").unwrap(), JsonValue::Number(42.0));
+    }
+
+    #[test]
+    fn trailing_garbage_is_rejected() {
+        assert!(parse_str("{}{}").is_err());
+    }
 }